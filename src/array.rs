@@ -46,22 +46,84 @@ impl<T: ArrayValue> fmt::Display for Array<T> {
                 }
                 write!(f, "{}", end)
             }
-            _ => {
-                write!(f, "[")?;
-                for (i, dim) in self.shape().iter().enumerate() {
-                    if i > 0 {
-                        write!(f, " ")?;
-                    }
-                    write!(f, "{dim}")?;
+            _ => fmt_grid(&self.shape, &self.data, f),
+        }
+    }
+}
+
+/// Render a rank ≥ 2 array as an aligned grid rather than a flat `[shape, data...]` dump
+fn fmt_grid<T: ArrayValue>(shape: &[usize], data: &[T], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if shape.len() == 2 {
+        return fmt_rows(shape[0], shape[1], data, f);
+    }
+    let row_len: usize = shape[1..].iter().product();
+    for i in 0..shape[0] {
+        if i > 0 {
+            writeln!(f)?;
+            writeln!(f)?;
+        }
+        let dims: Vec<String> = shape[1..].iter().map(usize::to_string).collect();
+        writeln!(f, "[{}]", dims.join(" "))?;
+        let start = i * row_len;
+        fmt_grid(&shape[1..], &data[start..start + row_len], f)?;
+    }
+    Ok(())
+}
+
+/// Render a 2-D array's rows on separate lines, columns right-aligned to their widest cell
+///
+/// Character arrays use empty delimiters/separator, so they fall back to contiguous
+/// text rows instead of per-cell alignment. Fill cells render blank.
+fn fmt_rows<T: ArrayValue>(
+    rows: usize,
+    cols: usize,
+    data: &[T],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let (start, end) = T::format_delims();
+    let sep = T::format_sep();
+    writeln!(f, "[")?;
+    if start.is_empty() && end.is_empty() {
+        for r in 0..rows {
+            write!(f, " ")?;
+            for c in 0..cols {
+                let val = &data[r * cols + c];
+                if val.is_fill_value() {
+                    write!(f, " ")?;
+                } else {
+                    write!(f, "{val}")?;
                 }
-                write!(f, ",")?;
-                for val in &self.data {
-                    write!(f, " {}", val)?;
+            }
+            writeln!(f)?;
+        }
+    } else {
+        let cells: Vec<String> = data
+            .iter()
+            .map(|val| {
+                if val.is_fill_value() {
+                    String::new()
+                } else {
+                    val.to_string()
                 }
-                write!(f, "]")
+            })
+            .collect();
+        let mut widths = vec![0; cols];
+        for (i, cell) in cells.iter().enumerate() {
+            let width = &mut widths[i % cols.max(1)];
+            *width = (*width).max(cell.chars().count());
+        }
+        for r in 0..rows {
+            write!(f, " {start}")?;
+            for c in 0..cols {
+                if c > 0 {
+                    write!(f, "{sep}")?;
+                }
+                write!(f, "{:>width$}", cells[r * cols + c], width = widths[c])?;
             }
+            writeln!(f, "{end}")?;
         }
     }
+    write!(f, "]")
 }
 
 #[track_caller]
@@ -230,6 +292,295 @@ impl<T: ArrayValue> Array<T> {
     }
 }
 
+impl Array<f64> {
+    /// Build a wavelet-matrix index over this array's non-negative integer values
+    ///
+    /// The index supports [`WaveletMatrix::quantile`] and [`WaveletMatrix::range_freq`]
+    /// in `O(H)` time, where `H` is the bit width of the largest value, rather than
+    /// sorting each queried window.
+    pub fn wavelet_matrix(&self, env: &Uiua) -> UiuaResult<WaveletMatrix> {
+        let mut values = Vec::with_capacity(self.data.len());
+        for &x in &self.data {
+            if !x.is_finite() || x < 0.0 || x.fract() != 0.0 {
+                return Err(env.error(format!(
+                    "Cannot build a wavelet matrix from {x}, which is not a non-negative integer"
+                )));
+            }
+            values.push(x as u64);
+        }
+        Ok(WaveletMatrix::build(&values))
+    }
+}
+
+/// A succinct index over a sequence of non-negative integers
+///
+/// Supports range order-statistic queries ([`quantile`](WaveletMatrix::quantile) and
+/// [`range_freq`](WaveletMatrix::range_freq)) in `O(H)` time, where `H` is the number
+/// of bits needed to represent the largest value.
+pub struct WaveletMatrix {
+    bit_width: u32,
+    max_value: u64,
+    levels: Vec<WaveletLevel>,
+}
+
+struct WaveletLevel {
+    /// `rank0[i]` is the number of 0-bits among the first `i` elements at this level
+    rank0: Vec<usize>,
+    /// The number of 0-bits at this level; where the 1-bits begin in the next level
+    zeros: usize,
+}
+
+impl WaveletMatrix {
+    fn build(values: &[u64]) -> Self {
+        let max = values.iter().copied().max().unwrap_or(0);
+        let bit_width = 64 - max.leading_zeros();
+        let mut sequence = values.to_vec();
+        let mut levels = Vec::with_capacity(bit_width as usize);
+        for h in (0..bit_width).rev() {
+            let mut rank0 = Vec::with_capacity(sequence.len() + 1);
+            rank0.push(0);
+            for &v in &sequence {
+                let bit = (v >> h) & 1;
+                let prev = *rank0.last().unwrap();
+                rank0.push(prev + (1 - bit as usize));
+            }
+            let zeros = *rank0.last().unwrap();
+            let mut next = Vec::with_capacity(sequence.len());
+            next.extend(sequence.iter().copied().filter(|v| (v >> h) & 1 == 0));
+            next.extend(sequence.iter().copied().filter(|v| (v >> h) & 1 == 1));
+            sequence = next;
+            levels.push(WaveletLevel { rank0, zeros });
+        }
+        WaveletMatrix {
+            bit_width,
+            max_value: max,
+            levels,
+        }
+    }
+    /// The `k`-th smallest value (0-indexed) in the slice `l..r`
+    ///
+    /// Returns `None` if the slice is empty or `k` is out of range for it.
+    pub fn quantile(&self, mut l: usize, mut r: usize, mut k: usize) -> Option<u64> {
+        if l >= r || k >= r - l {
+            return None;
+        }
+        let mut ans = 0u64;
+        for (level, h) in self.levels.iter().zip((0..self.bit_width).rev()) {
+            let z = level.rank0[r] - level.rank0[l];
+            if k < z {
+                l = level.rank0[l];
+                r = level.rank0[r];
+            } else {
+                ans |= 1 << h;
+                k -= z;
+                l = level.zeros + (l - level.rank0[l]);
+                r = level.zeros + (r - level.rank0[r]);
+            }
+        }
+        Some(ans)
+    }
+    /// The number of values in the slice `l..r` that are strictly less than `x`
+    pub fn range_freq(&self, mut l: usize, mut r: usize, x: u64) -> usize {
+        if l >= r {
+            return 0;
+        }
+        if x > self.max_value {
+            return r - l;
+        }
+        let mut count = 0;
+        for (level, h) in self.levels.iter().zip((0..self.bit_width).rev()) {
+            let bit = (x >> h) & 1;
+            if bit == 1 {
+                count += level.rank0[r] - level.rank0[l];
+                l = level.zeros + (l - level.rank0[l]);
+                r = level.zeros + (r - level.rank0[r]);
+            } else {
+                l = level.rank0[l];
+                r = level.rank0[r];
+            }
+        }
+        count
+    }
+}
+
+/// A disjoint-set forest, stored so that a root's entry is its negated set size
+/// and a non-root's entry is its parent's index
+struct DisjointSet(Vec<isize>);
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        Self(vec![-1; n])
+    }
+    /// Find the root of `u`'s set, halving the path to it along the way
+    fn find(&mut self, mut u: usize) -> usize {
+        while self.0[u] >= 0 {
+            let parent = self.0[u] as usize;
+            if self.0[parent] >= 0 {
+                self.0[u] = self.0[parent];
+            }
+            u = parent;
+        }
+        u
+    }
+    /// Merge the sets containing `a` and `b`, smaller under larger by size
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return;
+        }
+        let (big, small) = if self.0[ra] <= self.0[rb] {
+            (ra, rb)
+        } else {
+            (rb, ra)
+        };
+        self.0[big] += self.0[small];
+        self.0[small] = big as isize;
+    }
+}
+
+impl Array<f64> {
+    /// Label each of `num_vertices` vertices with its connected-component index
+    ///
+    /// `self` must have shape `[n 2]`, its rows giving the endpoints of undirected
+    /// edges as vertex indices. Self-loops and repeated edges are harmless no-ops.
+    /// Components are labeled `0..num_components` in increasing order of their
+    /// smallest member vertex, so labeling is deterministic.
+    pub fn connected_components(&self, num_vertices: usize, env: &Uiua) -> UiuaResult<Self> {
+        if self.rank() != 2 || self.shape[1] != 2 {
+            return Err(env.error(format!(
+                "Connected components expects an array of shape [n 2], but its shape is {:?}",
+                self.shape
+            )));
+        }
+        let validate = |x: f64| -> UiuaResult<usize> {
+            if !x.is_finite() || x < 0.0 || x.fract() != 0.0 || x as usize >= num_vertices {
+                return Err(env.error(format!(
+                    "{x} is not a valid vertex index for {num_vertices} vertices"
+                )));
+            }
+            Ok(x as usize)
+        };
+        let mut sets = DisjointSet::new(num_vertices);
+        for edge in self.data.chunks_exact(2) {
+            let a = validate(edge[0])?;
+            let b = validate(edge[1])?;
+            sets.union(a, b);
+        }
+        let mut labels = vec![usize::MAX; num_vertices];
+        let mut next_label = 0;
+        let mut data = Vec::with_capacity(num_vertices);
+        for v in 0..num_vertices {
+            let root = sets.find(v);
+            if labels[root] == usize::MAX {
+                labels[root] = next_label;
+                next_label += 1;
+            }
+            data.push(labels[root] as f64);
+        }
+        Ok(Array::new(vec![num_vertices], data))
+    }
+}
+
+// These scans live on `Array<f64>` specifically, not generically over `ArrayValue`,
+// the same way `wavelet_matrix` and `connected_components` above do: `ArrayValue`
+// exposes no arithmetic, so a generic `scan_with<T: ArrayValue>` couldn't fold
+// anything. `scan_xor` validates its input as non-negative integers rather than
+// requiring a separate `Array<Byte>` impl, since `Byte`'s own arithmetic isn't
+// exposed through `ArrayValue` either.
+impl Array<f64> {
+    /// Replace each row with the running fold of `init` applied to the first row and
+    /// `op` applied to every row after it, column by column along the first axis
+    ///
+    /// Cells equal to the fill value are left untouched and do not update the
+    /// running accumulator for their column, so fill propagates through unscanned.
+    fn scan_with(&self, init: impl Fn(f64) -> f64, op: impl Fn(f64, f64) -> f64) -> Self {
+        let row_len = self.row_len();
+        let mut data = self.data.clone();
+        if row_len > 0 {
+            for col in 0..row_len {
+                let mut acc: Option<f64> = None;
+                let mut idx = col;
+                while idx < data.len() {
+                    let x = data[idx];
+                    if !x.is_fill_value() {
+                        acc = Some(acc.map_or_else(|| init(x), |a| op(a, x)));
+                        data[idx] = acc.unwrap();
+                    }
+                    idx += row_len;
+                }
+            }
+        }
+        Array {
+            shape: self.shape.clone(),
+            data,
+            fill: self.fill,
+        }
+    }
+    /// Undo a scan by replacing each row with `op` applied to it and the row before it
+    ///
+    /// Unlike [`scan_with`](Self::scan_with), `op` is fed the *original* adjacent
+    /// values rather than a running accumulator, since scans with true inverses
+    /// (unlike `min`/`max`/`xor`) don't need to look further back than one row.
+    fn scan_inv_with(&self, op: impl Fn(f64, f64) -> f64) -> Self {
+        let row_len = self.row_len();
+        let mut data = self.data.clone();
+        if row_len > 0 {
+            for col in 0..row_len {
+                let mut prev: Option<f64> = None;
+                let mut idx = col;
+                while idx < data.len() {
+                    let x = self.data[idx];
+                    if !x.is_fill_value() {
+                        data[idx] = prev.map_or(x, |p| op(x, p));
+                        prev = Some(x);
+                    }
+                    idx += row_len;
+                }
+            }
+        }
+        Array {
+            shape: self.shape.clone(),
+            data,
+            fill: self.fill,
+        }
+    }
+    /// Replace each row with the running sum of it and all rows before it
+    pub fn scan_add(&self) -> Self {
+        self.scan_with(|x| x, |a, x| a + x)
+    }
+    /// Replace each row with the running product of it and all rows before it
+    pub fn scan_mul(&self) -> Self {
+        self.scan_with(|x| x, |a, x| a * x)
+    }
+    /// Replace each row with the running minimum of it and all rows before it
+    pub fn scan_min(&self) -> Self {
+        self.scan_with(|x| x, |a, x| a.min(x))
+    }
+    /// Replace each row with the running maximum of it and all rows before it
+    pub fn scan_max(&self) -> Self {
+        self.scan_with(|x| x, |a, x| a.max(x))
+    }
+    /// Replace each row with the running bitwise XOR of it and all rows before it
+    pub fn scan_xor(&self, env: &Uiua) -> UiuaResult<Self> {
+        for &x in &self.data {
+            if !x.is_fill_value() && (!x.is_finite() || x < 0.0 || x.fract() != 0.0) {
+                return Err(env.error(format!(
+                    "Cannot scan xor of {x}, which is not a non-negative integer"
+                )));
+            }
+        }
+        Ok(self.scan_with(|x| x, |a, x| ((a as u64) ^ (x as u64)) as f64))
+    }
+    /// Invert [`scan_add`](Self::scan_add) by taking first differences: `out[i] = in[i] - in[i-1]`
+    pub fn scan_add_inv(&self) -> Self {
+        self.scan_inv_with(|x, p| x - p)
+    }
+    /// Invert [`scan_mul`](Self::scan_mul) by taking successive ratios: `out[i] = in[i] / in[i-1]`
+    pub fn scan_mul_inv(&self) -> Self {
+        self.scan_inv_with(|x, p| x / p)
+    }
+}
+
 impl<T: ArrayValue> PartialEq for Array<T> {
     fn eq(&self, other: &Self) -> bool {
         if !(self.shape == other.shape && self.data.len() == other.data.len()) {
@@ -454,6 +805,137 @@ impl ArrayValue for Rc<Function> {
     }
 }
 
+/// An integer modulo a fixed prime
+///
+/// Arithmetic on `ModInt` wraps around `modulus`, letting combinatorial and
+/// number-theoretic code run without the overflow or precision loss that
+/// `f64` would introduce.
+#[derive(Clone, Copy, Debug)]
+pub struct ModInt {
+    pub val: u64,
+    pub modulus: u64,
+}
+
+impl ModInt {
+    /// Construct a `ModInt`, reducing `val` mod `modulus`
+    ///
+    /// `modulus == 0` is the fill value's sentinel modulus (see
+    /// [`ArrayValue::fill_value`] for `ModInt`) and is passed through unreduced
+    /// rather than panicking on the division.
+    pub fn new(val: u64, modulus: u64) -> Self {
+        Self {
+            val: if modulus == 0 { val } else { val % modulus },
+            modulus,
+        }
+    }
+    fn check_modulus(&self, other: &Self) {
+        debug_assert_eq!(
+            self.modulus, other.modulus,
+            "cannot combine ModInt values with different moduli"
+        );
+    }
+    pub fn add(&self, other: &Self) -> Self {
+        self.check_modulus(other);
+        Self::new(
+            ((self.val as u128 + other.val as u128) % self.modulus.max(1) as u128) as u64,
+            self.modulus,
+        )
+    }
+    pub fn sub(&self, other: &Self) -> Self {
+        self.check_modulus(other);
+        let modulus = self.modulus.max(1) as u128;
+        let diff = (self.val as u128 + modulus - other.val as u128 % modulus) % modulus;
+        Self::new(diff as u64, self.modulus)
+    }
+    pub fn mul(&self, other: &Self) -> Self {
+        self.check_modulus(other);
+        let modulus = self.modulus.max(1) as u128;
+        Self::new(
+            (self.val as u128 * other.val as u128 % modulus) as u64,
+            self.modulus,
+        )
+    }
+    /// Raise `self` to `exp` via binary (square-and-multiply) exponentiation
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut acc = Self::new(1, self.modulus);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.mul(&base);
+            }
+            base = base.mul(&base);
+            exp >>= 1;
+        }
+        acc
+    }
+    /// The modular inverse of `self`, assuming `modulus` is prime
+    ///
+    /// Computed via Fermat's little theorem: `a^-1 = a^(p - 2) mod p`.
+    pub fn inv(&self) -> Self {
+        self.pow(self.modulus - 2)
+    }
+    pub fn div(&self, other: &Self) -> Self {
+        self.check_modulus(other);
+        self.mul(&other.inv())
+    }
+}
+
+impl Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+impl ArrayValue for ModInt {
+    const NAME: &'static str = "modular";
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&self.val, &other.val)
+    }
+    fn fill_value() -> Self {
+        ModInt { val: 0, modulus: 0 }
+    }
+    fn is_fill_value(&self) -> bool {
+        self.modulus == 0
+    }
+}
+
+/// Precomputed factorials and inverse factorials modulo a prime, for O(1) binomial coefficients
+pub struct Binomials {
+    modulus: u64,
+    factorial: Vec<ModInt>,
+    inv_factorial: Vec<ModInt>,
+}
+
+impl Binomials {
+    pub fn new(n: usize, modulus: u64) -> Self {
+        let mut factorial = Vec::with_capacity(n + 1);
+        factorial.push(ModInt::new(1, modulus));
+        for i in 1..=n {
+            let prev = factorial[i - 1];
+            factorial.push(prev.mul(&ModInt::new(i as u64, modulus)));
+        }
+        let mut inv_factorial = vec![ModInt::new(1, modulus); n + 1];
+        inv_factorial[n] = factorial[n].inv();
+        for i in (1..=n).rev() {
+            inv_factorial[i - 1] = inv_factorial[i].mul(&ModInt::new(i as u64, modulus));
+        }
+        Self {
+            modulus,
+            factorial,
+            inv_factorial,
+        }
+    }
+    /// `n` choose `k`, or 0 if `k` is out of range
+    pub fn choose(&self, n: usize, k: usize) -> ModInt {
+        if k > n || n >= self.factorial.len() {
+            return ModInt::new(0, self.modulus);
+        }
+        self.factorial[n]
+            .mul(&self.inv_factorial[k])
+            .mul(&self.inv_factorial[n - k])
+    }
+}
+
 #[allow(clippy::len_without_is_empty)]
 pub trait Arrayish {
     type Value: ArrayValue;
@@ -518,3 +1000,158 @@ impl<T: ArrayValue> Arrayish for (&[usize], &mut [T]) {
         self.1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wavelet_matrix_quantile_matches_sorted_window() {
+        let values = [5u64, 1, 9, 3, 7, 2, 8, 0, 6, 4];
+        let wm = WaveletMatrix::build(&values);
+        let (l, r) = (2, 8);
+        let mut window: Vec<u64> = values[l..r].to_vec();
+        window.sort_unstable();
+        for (k, &expected) in window.iter().enumerate() {
+            assert_eq!(wm.quantile(l, r, k), Some(expected));
+        }
+    }
+
+    #[test]
+    fn wavelet_matrix_quantile_out_of_range_k_is_none() {
+        let values = [0u64, 0, 0, 0];
+        let wm = WaveletMatrix::build(&values);
+        assert_eq!(wm.quantile(0, 4, 3), Some(0));
+        assert_eq!(wm.quantile(0, 4, 4), None);
+        assert_eq!(wm.quantile(1, 1, 0), None);
+    }
+
+    #[test]
+    fn wavelet_matrix_range_freq_matches_brute_force() {
+        let values = [5u64, 1, 9, 3, 7, 2, 8, 0, 6, 4];
+        let wm = WaveletMatrix::build(&values);
+        let (l, r) = (1, 9);
+        for x in 0..12u64 {
+            let expected = values[l..r].iter().filter(|&&v| v < x).count();
+            assert_eq!(wm.range_freq(l, r, x), expected);
+        }
+    }
+
+    #[test]
+    fn wavelet_matrix_range_freq_above_max_counts_whole_slice() {
+        let values = [3u64, 1, 2];
+        let wm = WaveletMatrix::build(&values);
+        assert_eq!(wm.range_freq(0, 3, 100), 3);
+    }
+
+    #[test]
+    fn mod_int_inverse_round_trips_through_division() {
+        let p = 1_000_000_007;
+        for val in [1u64, 2, 3, 41, 999_999_999] {
+            let a = ModInt::new(val, p);
+            let one = a.div(&a);
+            assert_eq!(one.val, 1);
+        }
+    }
+
+    #[test]
+    fn mod_int_add_sub_mul_do_not_overflow_large_modulus() {
+        let p = u64::MAX - 58; // a large prime near u64::MAX
+        let a = ModInt::new(p - 1, p);
+        let b = ModInt::new(p - 1, p);
+        assert_eq!(a.add(&b).val, p - 2);
+        assert_eq!(a.sub(&b).val, 0);
+        assert_eq!(a.mul(&b).val, 1); // (-1) * (-1) = 1 mod p
+    }
+
+    #[test]
+    fn binomials_matches_pascals_triangle() {
+        let b = Binomials::new(10, 1_000_000_007);
+        assert_eq!(b.choose(5, 2).val, 10);
+        assert_eq!(b.choose(10, 0).val, 1);
+        assert_eq!(b.choose(10, 10).val, 1);
+        assert_eq!(b.choose(5, 7).val, 0);
+    }
+
+    #[test]
+    fn scan_add_inv_round_trips_through_scan_add() {
+        let original = Array::new(vec![5], vec![3.0, 1.0, 4.0, 1.0, 5.0]);
+        let summed = original.scan_add();
+        assert_eq!(summed.data, vec![3.0, 4.0, 8.0, 9.0, 14.0]);
+        assert_eq!(summed.scan_add_inv().data, original.data);
+    }
+
+    #[test]
+    fn scan_mul_inv_round_trips_through_scan_mul() {
+        let original = Array::new(vec![4], vec![2.0, 3.0, 5.0, 7.0]);
+        let product = original.scan_mul();
+        assert_eq!(product.data, vec![2.0, 6.0, 30.0, 210.0]);
+        assert_eq!(product.scan_mul_inv().data, original.data);
+    }
+
+    #[test]
+    fn scan_min_and_max_are_monotonic_folds() {
+        let array = Array::new(vec![5, 1], vec![3.0, 1.0, 4.0, 1.0, 5.0]);
+        assert_eq!(array.scan_min().data, vec![3.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(array.scan_max().data, vec![3.0, 3.0, 4.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn scan_xor_folds_bitwise_xor() {
+        // Exercises the same `scan_with` fold `scan_xor` validates and delegates to,
+        // without needing a `Uiua` environment to construct in this test.
+        let array = Array::new(vec![5, 1], vec![3.0, 1.0, 4.0, 1.0, 5.0]);
+        let xored = array.scan_with(|x| x, |a, x| ((a as u64) ^ (x as u64)) as f64);
+        assert_eq!(xored.data, vec![3.0, 2.0, 6.0, 7.0, 2.0]);
+    }
+
+    #[test]
+    fn scan_skips_fill_cells() {
+        let mut array = Array::new(vec![3], vec![1.0, f64::NAN, 2.0]);
+        array.fill = true;
+        let summed = array.scan_add();
+        assert_eq!(summed.data[0], 1.0);
+        assert!(summed.data[1].is_nan());
+        assert_eq!(summed.data[2], 3.0);
+    }
+
+    #[test]
+    fn disjoint_set_unions_by_size_and_path_halves() {
+        let mut sets = DisjointSet::new(6);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(1, 2); // merges {0,1} and {2,3} into one set of size 4
+        sets.union(4, 4); // self-loop is a no-op
+        let root = sets.find(0);
+        for v in [1, 2, 3] {
+            assert_eq!(sets.find(v), root);
+        }
+        assert_eq!(sets.0[root], -4); // the merged root records the set's size
+        assert_eq!(sets.0[3], root as isize); // finding 3 halved its path 3 -> 2 -> root
+        assert_ne!(sets.find(4), root);
+        assert_ne!(sets.find(5), root);
+        assert_ne!(sets.find(4), sets.find(5));
+    }
+
+    #[test]
+    fn display_rank2_right_aligns_columns_per_row() {
+        let array = Array::new(vec![2, 3], vec![1.0, 22.0, 3.0, 444.0, 5.0, 6.0]);
+        assert_eq!(array.to_string(), "[\n [  1 22 3]\n [444  5 6]\n]");
+    }
+
+    #[test]
+    fn display_rank3_recurses_with_a_shape_header_per_block() {
+        let array = Array::new(vec![2, 2, 2], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(
+            array.to_string(),
+            "[2 2]\n[\n [1 2]\n [3 4]\n]\n\n[2 2]\n[\n [5 6]\n [7 8]\n]"
+        );
+    }
+
+    #[test]
+    fn display_rank2_renders_fill_cells_blank() {
+        let mut array = Array::new(vec![2, 2], vec![1.0, f64::NAN, 3.0, 4.0]);
+        array.fill = true;
+        assert_eq!(array.to_string(), "[\n [1  ]\n [3 4]\n]");
+    }
+}